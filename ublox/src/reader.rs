@@ -0,0 +1,289 @@
+//! Adapters that pump bytes from a `Read` source into a [`Parser`], so
+//! callers don't have to hand-write the read/consume/drain loop that every
+//! serial-port integration otherwise ends up duplicating.
+
+use crate::{
+    error::ParserError,
+    parser::{Parser, UnderlyingBuffer},
+    ubx_packets::PacketRef,
+};
+
+/// Size of the scratch buffer each read is made into before being fed to
+/// the [`Parser`]. Chosen to comfortably hold a handful of typical UBX
+/// packets per read without being wasteful on constrained targets.
+const READ_CHUNK_LEN: usize = 256;
+
+/// Error returned by [`ParserReader::next_packet`] and
+/// [`AsyncParserReader::next_packet`].
+#[derive(Debug)]
+pub enum ParserReaderError<E> {
+    /// The underlying reader returned an error.
+    Io(E),
+    /// The underlying reader reached end-of-stream with no packet pending.
+    Eof,
+    /// The parser reported an error for a UBX frame, e.g. a bad checksum or
+    /// a frame too large for the parser's buffer.
+    Parser(ParserError),
+}
+
+/// Wraps a [`Parser`] and a blocking [`embedded_io::Read`] source, reading
+/// chunks into a scratch buffer and feeding them to the parser until a
+/// packet is decoded.
+///
+/// With `embedded_io`'s own `std` feature enabled, any `std::io::Read` type
+/// (e.g. `serialport::SerialPort`) implements `embedded_io::Read` and can be
+/// used here directly.
+#[cfg(feature = "embedded-io")]
+pub struct ParserReader<T: UnderlyingBuffer, R> {
+    parser: Parser<T>,
+    reader: R,
+    scratch: [u8; READ_CHUNK_LEN],
+}
+
+#[cfg(feature = "embedded-io")]
+impl<T: UnderlyingBuffer, R: embedded_io::Read> ParserReader<T, R> {
+    pub fn new(parser: Parser<T>, reader: R) -> Self {
+        Self {
+            parser,
+            reader,
+            scratch: [0; READ_CHUNK_LEN],
+        }
+    }
+
+    /// Reads and feeds bytes into the parser until a packet is decoded,
+    /// then hands it to `f` and returns whatever `f` returns.
+    ///
+    /// `f` takes the packet by callback rather than `next_packet` handing
+    /// it back directly, because `PacketRef` borrows from the parser's
+    /// internal buffer: that buffer is free to be drained and reused the
+    /// moment this call returns, so the packet can't be allowed to outlive
+    /// it.
+    pub fn next_packet<F, U>(&mut self, f: F) -> Result<U, ParserReaderError<R::Error>>
+    where
+        F: FnOnce(PacketRef<'_>) -> U,
+    {
+        let mut pending: &[u8] = &[];
+        loop {
+            let mut it = self.parser.consume(pending);
+            if let Some(result) = it.next() {
+                return result.map(f).map_err(ParserReaderError::Parser);
+            }
+            drop(it);
+
+            let n = self
+                .reader
+                .read(&mut self.scratch)
+                .map_err(ParserReaderError::Io)?;
+            if n == 0 {
+                return Err(ParserReaderError::Eof);
+            }
+            pending = &self.scratch[..n];
+        }
+    }
+}
+
+/// Wraps a [`Parser`] and an async [`embedded_io_async::Read`] source,
+/// mirroring [`ParserReader`] for async runtimes.
+#[cfg(feature = "embedded-io-async")]
+pub struct AsyncParserReader<T: UnderlyingBuffer, R> {
+    parser: Parser<T>,
+    reader: R,
+    scratch: [u8; READ_CHUNK_LEN],
+}
+
+#[cfg(feature = "embedded-io-async")]
+impl<T: UnderlyingBuffer, R: embedded_io_async::Read> AsyncParserReader<T, R> {
+    pub fn new(parser: Parser<T>, reader: R) -> Self {
+        Self {
+            parser,
+            reader,
+            scratch: [0; READ_CHUNK_LEN],
+        }
+    }
+
+    /// Reads and feeds bytes into the parser until a packet is decoded,
+    /// then hands it to `f` and returns whatever `f` returns. See
+    /// [`ParserReader::next_packet`] for why this is callback-based rather
+    /// than returning the packet directly.
+    pub async fn next_packet<F, U>(&mut self, f: F) -> Result<U, ParserReaderError<R::Error>>
+    where
+        F: FnOnce(PacketRef<'_>) -> U,
+    {
+        let mut pending: &[u8] = &[];
+        loop {
+            let mut it = self.parser.consume(pending);
+            if let Some(result) = it.next() {
+                return result.map(f).map_err(ParserReaderError::Parser);
+            }
+            drop(it);
+
+            let n = self
+                .reader
+                .read(&mut self.scratch)
+                .await
+                .map_err(ParserReaderError::Io)?;
+            if n == 0 {
+                return Err(ParserReaderError::Eof);
+            }
+            pending = &self.scratch[..n];
+        }
+    }
+
+    /// Turns this reader into a [`futures::Stream`] of values produced by
+    /// `f` from each decoded packet, ending the first time `next_packet`
+    /// returns `Eof`.
+    ///
+    /// Takes `f` rather than yielding `PacketRef` directly for the same
+    /// reason as [`Self::next_packet`]: a `Stream::Item` has to be an owned
+    /// value the caller can hold onto across polls, but `PacketRef` borrows
+    /// from a buffer this reader reuses on the very next packet.
+    #[cfg(feature = "futures")]
+    pub fn into_stream<F, U>(
+        self,
+        f: F,
+    ) -> impl futures::Stream<Item = Result<U, ParserReaderError<R::Error>>>
+    where
+        F: FnMut(PacketRef<'_>) -> U,
+    {
+        futures::stream::unfold((Some(self), f), |(state, mut f)| async move {
+            let mut reader = state?;
+            match reader.next_packet(&mut f).await {
+                Err(ParserReaderError::Eof) => None,
+                result => Some((result, (Some(reader), f))),
+            }
+        })
+    }
+}
+
+#[cfg(all(test, feature = "embedded-io"))]
+mod test {
+    use super::*;
+    use crate::parser::Parser;
+
+    /// A mock `embedded_io::Read` that hands out a fixed sequence of reads,
+    /// one slice per `read()` call, so tests can exercise a packet split
+    /// across multiple reads.
+    struct MockReader<'a> {
+        chunks: std::vec::Vec<&'a [u8]>,
+    }
+
+    impl embedded_io::ErrorType for MockReader<'_> {
+        type Error = core::convert::Infallible;
+    }
+
+    impl embedded_io::Read for MockReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            if self.chunks.is_empty() {
+                return Ok(0);
+            }
+            let chunk = self.chunks.remove(0);
+            buf[..chunk.len()].copy_from_slice(chunk);
+            Ok(chunk.len())
+        }
+    }
+
+    #[test]
+    fn next_packet_assembles_a_packet_split_across_reads() {
+        let ubx = [0xb5, 0x62, 0x5, 0x1, 0x2, 0x0, 0x4, 0x5, 0x11, 0x38];
+        let reader = MockReader {
+            chunks: std::vec![&ubx[..3], &ubx[3..]],
+        };
+        let mut reader = ParserReader::new(Parser::default(), reader);
+
+        let is_ack_ack = reader
+            .next_packet(|packet| matches!(packet, PacketRef::AckAck(_)))
+            .unwrap();
+        assert!(is_ack_ack);
+    }
+
+    #[test]
+    fn next_packet_returns_eof_on_a_zero_length_read() {
+        let reader = MockReader { chunks: std::vec![] };
+        let mut reader = ParserReader::new(Parser::default(), reader);
+
+        match reader.next_packet(|_packet| ()) {
+            Err(ParserReaderError::Eof) => {
+                // We're good
+            }
+            _ => assert!(false),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "embedded-io-async", feature = "futures"))]
+mod async_test {
+    use super::*;
+    use crate::parser::Parser;
+
+    /// A mock `embedded_io_async::Read` that hands out a fixed sequence of
+    /// reads, one slice per `read()` call, so tests can exercise a packet
+    /// split across multiple reads.
+    struct MockReader<'a> {
+        chunks: std::vec::Vec<&'a [u8]>,
+    }
+
+    impl embedded_io::ErrorType for MockReader<'_> {
+        type Error = core::convert::Infallible;
+    }
+
+    impl embedded_io_async::Read for MockReader<'_> {
+        async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            if self.chunks.is_empty() {
+                return Ok(0);
+            }
+            let chunk = self.chunks.remove(0);
+            buf[..chunk.len()].copy_from_slice(chunk);
+            Ok(chunk.len())
+        }
+    }
+
+    #[test]
+    fn next_packet_assembles_a_packet_split_across_reads() {
+        let ubx = [0xb5, 0x62, 0x5, 0x1, 0x2, 0x0, 0x4, 0x5, 0x11, 0x38];
+        let reader = MockReader {
+            chunks: std::vec![&ubx[..3], &ubx[3..]],
+        };
+        let mut reader = AsyncParserReader::new(Parser::default(), reader);
+
+        let is_ack_ack = futures::executor::block_on(
+            reader.next_packet(|packet| matches!(packet, PacketRef::AckAck(_))),
+        )
+        .unwrap();
+        assert!(is_ack_ack);
+    }
+
+    #[test]
+    fn next_packet_returns_eof_on_a_zero_length_read() {
+        let reader = MockReader { chunks: std::vec![] };
+        let mut reader = AsyncParserReader::new(Parser::default(), reader);
+
+        match futures::executor::block_on(reader.next_packet(|_packet| ())) {
+            Err(ParserReaderError::Eof) => {
+                // We're good
+            }
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    fn into_stream_yields_values_until_eof() {
+        use futures::StreamExt;
+
+        let ubx = [0xb5, 0x62, 0x5, 0x1, 0x2, 0x0, 0x4, 0x5, 0x11, 0x38];
+        let reader = MockReader {
+            chunks: std::vec![&ubx, &ubx],
+        };
+        let reader = AsyncParserReader::new(Parser::default(), reader);
+
+        let results: std::vec::Vec<_> = futures::executor::block_on(
+            reader
+                .into_stream(|packet| matches!(packet, PacketRef::AckAck(_)))
+                .collect(),
+        );
+
+        assert_eq!(results.len(), 2);
+        for result in results {
+            assert_eq!(result.unwrap(), true);
+        }
+    }
+}